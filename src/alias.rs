@@ -0,0 +1,184 @@
+//! Exact alias-method sampling over integer weights.
+//!
+//! Implements Vose's alias method over integer weights, scaled up to an exact common
+//! denominator, so the resulting [`AliasTable`] samples the distribution the weights describe
+//! exactly, with no floating-point drift.
+
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+
+/// Types that can be used as sampling weights in an [`AliasTable`].
+///
+/// Implemented for the built-in integer types. Weights must be non-negative.
+pub trait IntegerWeight: Copy
+{
+	/// Converts this weight to its `u64` representation for building an [`AliasTable`].
+	fn as_u64(self) -> u64;
+}
+
+macro_rules! impl_integer_weight_unsigned {
+	($($ty:ty),*) => {
+		$(
+			impl IntegerWeight for $ty
+			{
+				fn as_u64(self) -> u64 { self as u64 }
+			}
+		)*
+	};
+}
+impl_integer_weight_unsigned!(u8, u16, u32, u64, usize);
+
+macro_rules! impl_integer_weight_signed {
+	($($ty:ty),*) => {
+		$(
+			impl IntegerWeight for $ty
+			{
+				fn as_u64(self) -> u64
+				{
+					assert!(self >= 0, "weights must be non-negative");
+					self as u64
+				}
+			}
+		)*
+	};
+}
+impl_integer_weight_signed!(i8, i16, i32, i64, isize);
+
+
+/// Precomputed alias table for exact weighted sampling, built with Vose's alias method over
+/// integer weights.
+///
+/// Every probability stored here is an exact fraction of `threshold`, rather than a floating
+/// point cutoff, so repeated sampling never drifts from the distribution the weights describe.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AliasTable
+{
+	/// `prob[i]`: the threshold (out of `threshold`) below which a coin flip lands on `i` itself.
+	prob: Vec<u64>,
+	/// `alias[i]`: the index returned when the coin flip for `i` misses.
+	alias: Vec<usize>,
+	/// The common denominator every `prob` entry and coin flip is scaled to.
+	threshold: u64,
+}
+
+impl AliasTable
+{
+	/// Builds an alias table from integer weights using Vose's alias method.
+	///
+	/// Every weight is multiplied by `lcm(n, total) / total`, so the weights sum to
+	/// `lcm(n, total)` and the per-bucket fill threshold `lcm(n, total) / n` is an exact integer,
+	/// keeping the whole construction - and every later sample - in exact integer arithmetic.
+	///
+	/// Not a `const fn`: `prob` and `alias` are `Vec`s sized to the input, which heap-allocate and
+	/// so can't be built at compile time. A table can still be computed once at startup (e.g.
+	/// behind a `once_cell::sync::Lazy`) and reused for the life of the program.
+	///
+	/// # Panics
+	///
+	/// Panics if `weights` is empty or every weight is zero.
+	pub fn new<W>(weights: &[W]) -> Self
+	where W: IntegerWeight
+	{
+		let n = weights.len();
+		assert!(n > 0, "AliasTable needs at least one weight");
+		let total: u64 = weights.iter().map(|w| w.as_u64()).sum();
+		assert!(total > 0, "AliasTable needs at least one non-zero weight");
+
+		let scaled_total = lcm(n as u64, total);
+		let scale = scaled_total / total;
+		let threshold = scaled_total / n as u64;
+
+		let mut residual: Vec<u64> = weights.iter().map(|w| w.as_u64() * scale).collect();
+		let mut prob = vec![0u64; n];
+		let mut alias = vec![0usize; n];
+
+		let mut small: Vec<usize> = vec![];
+		let mut large: Vec<usize> = vec![];
+		for (i, &r) in residual.iter().enumerate()
+		{
+			if r < threshold { small.push(i); } else { large.push(i); }
+		}
+
+		while !small.is_empty() && !large.is_empty()
+		{
+			let small_idx = small.pop().unwrap();
+			let large_idx = large.pop().unwrap();
+			prob[small_idx] = residual[small_idx];
+			alias[small_idx] = large_idx;
+			residual[large_idx] = residual[large_idx] + residual[small_idx] - threshold;
+			if residual[large_idx] < threshold { small.push(large_idx); } else { large.push(large_idx); }
+		}
+		// leftover entries (exact integer arithmetic leaves them exactly full) fill their own slot
+		for i in large.into_iter().chain(small)
+		{
+			prob[i] = threshold;
+		}
+
+		Self { prob, alias, threshold }
+	}
+
+	/// Samples a single index in `[0, n)`, weighted according to the table.
+	pub fn sample<R>(&self, rng: &mut R) -> usize
+	where R: Rng + ?Sized
+	{
+		let i = rng.gen_range(0..self.prob.len());
+		if rng.gen_range(0..self.threshold) < self.prob[i] { i } else { self.alias[i] }
+	}
+}
+
+
+/// Greatest common divisor, via the Euclidean algorithm.
+const fn gcd(a: u64, b: u64) -> u64
+{
+	if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Least common multiple.
+const fn lcm(a: u64, b: u64) -> u64
+{
+	a / gcd(a, b) * b
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use approx::assert_ulps_eq;
+	use rand::thread_rng;
+
+	const SAMPLE_COUNT: usize = 3000;
+	const EPSILON: f32 = 0.2;
+
+	/// Verify that equal weights produce every index with roughly equal frequency - here every
+	/// bucket starts out full, so sampling relies entirely on the leftover-bucket handling.
+	#[test]
+	fn sample_equal_weights()
+	{
+		let table = AliasTable::new(&[1u32, 1]);
+		let mut rng = thread_rng();
+		let mut counts = [0usize; 2];
+		for _ in 0..SAMPLE_COUNT
+		{
+			counts[table.sample(&mut rng)] += 1;
+		}
+		assert_ulps_eq!(1.0, counts[0] as f32 / counts[1] as f32, epsilon = EPSILON);
+	}
+
+	/// Verify that skewed weights produce index 1 roughly three times as often as index 0.
+	#[test]
+	fn sample_skewed_weights()
+	{
+		let table = AliasTable::new(&[1u32, 3]);
+		let mut rng = thread_rng();
+		let mut counts = [0usize; 2];
+		for _ in 0..SAMPLE_COUNT
+		{
+			counts[table.sample(&mut rng)] += 1;
+		}
+		assert_ulps_eq!(3.0, counts[1] as f32 / counts[0] as f32, epsilon = EPSILON);
+	}
+}
@@ -0,0 +1,155 @@
+//! Templates for rendering a sampled full name into a display string.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+
+/// Field order for rendering a full name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NameOrder
+{
+	/// e.g. "John Smith"
+	GivenFirst,
+	/// e.g. "Tanaka Kenji"
+	FamilyFirst,
+}
+
+/// Casing applied to each rendered name field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NameCasing
+{
+	/// Render the name exactly as stored.
+	AsIs,
+	/// Render the name in all uppercase.
+	Upper,
+	/// Render the name in all lowercase.
+	Lower,
+}
+
+/// An honorific or title slotted onto a rendered name, either before or directly after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Honorific
+{
+	/// Rendered before the name, followed by the format's separator, e.g. "Mr. John Smith".
+	Prefix(String),
+	/// Rendered directly after the name with no separator, e.g. "Tanaka-san".
+	Suffix(String),
+}
+
+/// Describes how to assemble a sampled given/family name pair into a display string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NameFormat
+{
+	order: NameOrder,
+	separator: String,
+	casing: NameCasing,
+	honorific: Option<Honorific>,
+}
+
+impl NameFormat
+{
+	/// Creates a new format with the given field order and separator, no honorific and no casing
+	/// changes.
+	pub fn new(order: NameOrder, separator: impl Into<String>) -> Self
+	{
+		Self { order, separator: separator.into(), casing: NameCasing::AsIs, honorific: None }
+	}
+
+	/// Sets the casing applied to each name field.
+	pub fn with_casing(mut self, casing: NameCasing) -> Self
+	{
+		self.casing = casing;
+		self
+	}
+
+	/// Adds an honorific or title slot to the format.
+	pub fn with_honorific(mut self, honorific: Honorific) -> Self
+	{
+		self.honorific = Some(honorific);
+		self
+	}
+
+	fn render_field(&self, field: &str) -> String
+	{
+		match self.casing
+		{
+			NameCasing::AsIs => field.to_string(),
+			NameCasing::Upper => field.to_uppercase(),
+			NameCasing::Lower => field.to_lowercase(),
+		}
+	}
+
+	/// Assembles a given/family name pair into a display string according to this format.
+	pub fn render(&self, given: &str, family: &str) -> String
+	{
+		let given = self.render_field(given);
+		let family = self.render_field(family);
+		let name = match self.order
+		{
+			NameOrder::GivenFirst => format!("{given}{}{family}", self.separator),
+			NameOrder::FamilyFirst => format!("{family}{}{given}", self.separator),
+		};
+		match &self.honorific
+		{
+			Some(Honorific::Prefix(title)) => format!("{title}{}{name}", self.separator),
+			Some(Honorific::Suffix(title)) => format!("{name}{title}"),
+			None => name,
+		}
+	}
+}
+
+impl Default for NameFormat
+{
+	/// Given name first, separated by a single space, no honorific.
+	fn default() -> Self
+	{
+		Self::new(NameOrder::GivenFirst, " ")
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn default_format_is_given_first()
+	{
+		let format = NameFormat::default();
+		assert_eq!("John Smith", format.render("John", "Smith"));
+	}
+
+	#[test]
+	fn family_first()
+	{
+		let format = NameFormat::new(NameOrder::FamilyFirst, " ");
+		assert_eq!("Tanaka Kenji", format.render("Kenji", "Tanaka"));
+	}
+
+	#[test]
+	fn prefix_honorific()
+	{
+		let format = NameFormat::default().with_honorific(Honorific::Prefix("Mr.".to_string()));
+		assert_eq!("Mr. John Smith", format.render("John", "Smith"));
+	}
+
+	#[test]
+	fn suffix_honorific()
+	{
+		let format = NameFormat::new(NameOrder::FamilyFirst, " ")
+			.with_honorific(Honorific::Suffix("-san".to_string()));
+		assert_eq!("Tanaka Kenji-san", format.render("Kenji", "Tanaka"));
+	}
+
+	#[test]
+	fn casing()
+	{
+		let format = NameFormat::default().with_casing(NameCasing::Upper);
+		assert_eq!("JOHN SMITH", format.render("John", "Smith"));
+	}
+}
@@ -1,33 +1,112 @@
 //! # Name Generator
-//! 
+//!
 //! Crate for generating given name/family name pairs at random from weighted lists.
-//! 
+//!
 //! This crate is intended to be used only for my own game development projects, but I want to keep
 //! it as generic and engine agnostic as possible in case someone else has a need for it, and in
 //! case I ever need to change game engines.
+//!
+//! ## Feature flags
+//!
+//! - `builtin` (default): compiles in the [`presets`] module of ready-made locale name lists.
+//!   Disable it if you only ever load your own data, so you don't pay to embed tables you don't use.
+//! - `serde`: derives `Serialize`/`Deserialize` for the list types and adds
+//!   [`WeightedNameList::from_json_reader`] and [`WeightedNameList::from_csv_reader`] for loading
+//!   name data from external files instead of compiling it in.
+//! - `fluent`: adds the [`localize`] module, for rendering a sampled name through a Fluent
+//!   bundle so it can produce a different display string per locale.
 
 use rand::Rng;
-use rand_distr::{ Distribution, WeightedAliasIndex, weighted_alias::AliasableWeight };
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub mod alias;
+pub mod format;
+#[cfg(feature = "fluent")]
+pub mod localize;
+pub mod parse;
+#[cfg(feature = "builtin")]
+pub mod presets;
+
+use alias::{AliasTable, IntegerWeight};
+use format::NameFormat;
+
+
+
+/// A character's gender, used to select the appropriate given name pool out of a
+/// [`GenderedNameList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Gender
+{
+	Masculine,
+	Feminine,
+	/// Selects the unisex pool of a [`GenderedNameList`], if one was provided.
+	Unisex,
+}
+
+
+
+/// Weighted list of names split into masculine, feminine, and unisex pools, so a name can be
+/// sampled that's appropriate for a given [`Gender`]. Any pool can be left unset, e.g. for a list
+/// parsed from a corpus with no gender information attached (see
+/// [`WeightedFullNameList::from_raw_names`]).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenderedNameList<S>
+{
+	masculine_names: Option<WeightedNameList<S>>,
+	feminine_names: Option<WeightedNameList<S>>,
+	unisex_names: Option<WeightedNameList<S>>,
+}
+
+impl<S> GenderedNameList<S>
+{
+	/// Creates a new instance from the given pools. Any pool left as `None` will cause
+	/// [`Self::sample`] to panic if that [`Gender`] is requested.
+	pub fn new(masculine_names: Option<WeightedNameList<S>>, feminine_names: Option<WeightedNameList<S>>, unisex_names: Option<WeightedNameList<S>>) -> Self
+	{
+		Self { masculine_names, feminine_names, unisex_names }
+	}
+
+	/// Samples a single random entry from the pool matching the requested `gender`.
+	///
+	/// # Panics
+	///
+	/// Panics if no pool was provided for the requested `gender`.
+	pub fn sample<R>(&self, rng: &mut R, gender: Gender) -> &S
+	where R: Rng + ?Sized
+	{
+		let pool = match gender
+		{
+			Gender::Masculine => &self.masculine_names,
+			Gender::Feminine => &self.feminine_names,
+			Gender::Unisex => &self.unisex_names,
+		};
+		pool.as_ref()
+			.expect("no name pool was provided for the requested gender")
+			.sample(rng)
+	}
+}
 
 
 
 /// Weighted list of singular names. Use if you don't need full names for your game (e.g. only a
 /// character's family name is used.)
-pub struct WeightedNameList<S, W>
-where W: AliasableWeight
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeightedNameList<S>
 {
 	names: Vec<S>,
-	weights: WeightedAliasIndex<W>,
+	weights: AliasTable,
 }
 
-impl<S, W> WeightedNameList<S, W>
-where W: AliasableWeight
+impl<S> WeightedNameList<S>
 {
-	pub fn new(names: Vec<S>, weights: Vec<W>) -> Self
+	pub fn new<W>(names: Vec<S>, weights: Vec<W>) -> Self
+	where W: IntegerWeight
 	{
 		Self {
+			weights: AliasTable::new(&weights),
 			names,
-			weights: WeightedAliasIndex::new(weights).unwrap()
 		}
 	}
 
@@ -39,8 +118,35 @@ where W: AliasableWeight
 	}
 }
 
-impl<R, S, W> From<Vec<(R, W)>> for WeightedNameList<S, W>
-where R: Into<S>, S: Clone, W: AliasableWeight
+#[cfg(feature = "serde")]
+impl<S> WeightedNameList<S>
+{
+	/// Loads a weighted name list from a JSON array of `(name, weight)` pairs, e.g.
+	/// `[["Foo", 2], ["Bar", 1]]`.
+	pub fn from_json_reader<Reader, W>(reader: Reader) -> serde_json::Result<Self>
+	where Reader: std::io::Read, S: for<'de> Deserialize<'de> + Clone, W: for<'de> Deserialize<'de> + IntegerWeight
+	{
+		let rows: Vec<(S, W)> = serde_json::from_reader(reader)?;
+		Ok(Self::from(rows))
+	}
+
+	/// Loads a weighted name list from CSV rows of `name,weight`, with no header row.
+	pub fn from_csv_reader<Reader, W>(reader: Reader) -> csv::Result<Self>
+	where Reader: std::io::Read, S: for<'de> Deserialize<'de> + Clone, W: for<'de> Deserialize<'de> + IntegerWeight
+	{
+		let mut csv_reader = csv::ReaderBuilder::new().has_headers(false).from_reader(reader);
+		let mut rows = vec![];
+		for record in csv_reader.deserialize()
+		{
+			let (name, weight): (S, W) = record?;
+			rows.push((name, weight));
+		}
+		Ok(Self::from(rows))
+	}
+}
+
+impl<R, S, W> From<Vec<(R, W)>> for WeightedNameList<S>
+where R: Into<S>, S: Clone, W: IntegerWeight
 {
 	fn from(value: Vec<(R, W)>) -> Self {
 		let mut name_vec = vec![];
@@ -54,8 +160,8 @@ where R: Into<S>, S: Clone, W: AliasableWeight
 	}
 }
 
-impl<R, S, W> From<(Vec<R>, Vec<W>)> for WeightedNameList<S, W>
-where R: Into<S>, S: Clone, W: AliasableWeight
+impl<R, S, W> From<(Vec<R>, Vec<W>)> for WeightedNameList<S>
+where R: Into<S>, S: Clone, W: IntegerWeight
 {
 	fn from(value: (Vec<R>, Vec<W>)) -> Self {
 		// separate input values
@@ -81,40 +187,58 @@ where R: Into<S>, S: Clone, W: AliasableWeight
 
 
 
-pub struct WeightedFullNameList<S, W>
-where W: AliasableWeight
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeightedFullNameList<S>
 {
-	given_names: WeightedNameList<S, W>,
-	family_names: WeightedNameList<S, W>,
+	given_names: GenderedNameList<S>,
+	family_names: WeightedNameList<S>,
 }
 
-impl<S, W> WeightedFullNameList<S, W>
-where W: AliasableWeight
+impl<S> WeightedFullNameList<S>
 {
-	/// Creates a new instance with the provided name lists
-	pub fn new(given_names: WeightedNameList<S, W>, family_names: WeightedNameList<S, W>) -> Self
+	/// Creates a new instance with the provided name lists. Family names are treated as
+	/// gender-neutral, so only the given names need to be split by [`Gender`].
+	pub fn new(given_names: GenderedNameList<S>, family_names: WeightedNameList<S>) -> Self
 	{
 		Self { given_names, family_names }
 	}
 
 	/// Samples a random full name from the lists, returning a tuple with a given name and family
-	/// name, in that order.
-	/// 
+	/// name, in that order. The given name is drawn from the pool matching the requested
+	/// `gender`; the family name is always gender-neutral.
+	///
 	/// ```
 	/// # use rand::thread_rng;
-	/// # use namegen::{WeightedNameList, WeightedFullNameList};
-	/// let given_names: WeightedNameList<String, usize> = WeightedNameList::from(vec![("Foo", 1)]);
-	/// let family_names: WeightedNameList<String, usize> = WeightedNameList::from(vec![("Bar", 1)]);
+	/// # use namegen::{Gender, GenderedNameList, WeightedNameList, WeightedFullNameList};
+	/// let given_names = GenderedNameList::new(
+	///     Some(WeightedNameList::from(vec![("Foo", 1)])),
+	///     Some(WeightedNameList::from(vec![("Fay", 1)])),
+	///     None,
+	/// );
+	/// let family_names: WeightedNameList<String> = WeightedNameList::from(vec![("Bar", 1)]);
 	/// let name_list = WeightedFullNameList::new(given_names, family_names);
 	/// let mut rng = thread_rng();
-	/// let (given_name, family_name) = name_list.sample(&mut rng);
+	/// let (given_name, family_name) = name_list.sample(&mut rng, Gender::Masculine);
 	/// assert_eq!("Foo", given_name);
 	/// assert_eq!("Bar", family_name);
 	/// ```
-	pub fn sample<R>(&self, rng: &mut R) -> (&S, &S)
+	pub fn sample<R>(&self, rng: &mut R, gender: Gender) -> (&S, &S)
+	where R: Rng + ?Sized
+	{
+		(self.given_names.sample(rng, gender), self.family_names.sample(rng))
+	}
+}
+
+impl<S> WeightedFullNameList<S>
+where S: AsRef<str>
+{
+	/// Samples a full name and assembles it into a display string using `format`, so locales
+	/// like Japanese (family name first) don't need manual string juggling at the call site.
+	pub fn sample_formatted<R>(&self, rng: &mut R, gender: Gender, format: &NameFormat) -> String
 	where R: Rng + ?Sized
 	{
-		(self.given_names.sample(rng), self.family_names.sample(rng))
+		let (given, family) = self.sample(rng, gender);
+		format.render(given.as_ref(), family.as_ref())
 	}
 }
 
@@ -137,13 +261,13 @@ mod tests
 
 		#[test]
 		/// Verify that randomly sampling enough names will result in roughly the same distribution defined by the weights
-		/// 
+		///
 		/// In this case, tests that the name `"Foo"` occurs approximately twice as often as `"Bar"`
 		fn sample()
 		{
 			let mut rng = thread_rng();
 			let test_data = vec![("Foo", 2), ("Bar", 1)];
-			let name_list = WeightedNameList::<String, usize>::from(test_data);
+			let name_list = WeightedNameList::<String>::from(test_data);
 			let mut count_foo = 0;
 			let mut count_bar = 0;
 			for _ in 0..NAME_COUNT
@@ -161,7 +285,7 @@ mod tests
 		fn from_vec()
 		{
 			let test_data = vec![("Foo", 2), ("Bar", 3), ("Baz", 4)];
-			let _result: WeightedNameList<String, usize> = WeightedNameList::from(test_data);
+			let _result: WeightedNameList<String> = WeightedNameList::from(test_data);
 		}
 
 		/// Verify that you can turn a vector of names and a separate vector of weights into a [`WeightedNameList`]
@@ -170,7 +294,7 @@ mod tests
 		{
 			let test_data_names = vec!["Foo", "Bar", "Baz"];
 			let test_data_weights = vec![1, 2, 3];
-			let _result: WeightedNameList<String, usize> = WeightedNameList::from((test_data_names, test_data_weights));
+			let _result: WeightedNameList<String> = WeightedNameList::from((test_data_names, test_data_weights));
 		}
 
 		/// Verify that when you try to turn two vectors of differing lengths into a [`WeightedNameList`], the program panics
@@ -180,7 +304,50 @@ mod tests
 		{
 			let test_data_names = vec!["Foo", "Bar", "Baz"];
 			let test_data_weights = vec![1, 2, 3, 4];
-			let _result: WeightedNameList<String, usize> = WeightedNameList::from((test_data_names, test_data_weights));
+			let _result: WeightedNameList<String> = WeightedNameList::from((test_data_names, test_data_weights));
+		}
+	}
+
+
+	mod gendered_name
+	{
+		use super::*;
+
+		/// Verify that sampling with [`Gender::Masculine`] and [`Gender::Feminine`] draws from
+		/// the matching pool only
+		#[test]
+		fn sample()
+		{
+			let masculine_names = WeightedNameList::from(vec![("Foo", 1)]);
+			let feminine_names = WeightedNameList::from(vec![("Bar", 1)]);
+			let name_list: GenderedNameList<String> = GenderedNameList::new(Some(masculine_names), Some(feminine_names), None);
+			let mut rng = thread_rng();
+			assert_eq!("Foo", name_list.sample(&mut rng, Gender::Masculine));
+			assert_eq!("Bar", name_list.sample(&mut rng, Gender::Feminine));
+		}
+
+		/// Verify that sampling with [`Gender::Unisex`] draws from the unisex pool when one is provided
+		#[test]
+		fn sample_unisex()
+		{
+			let masculine_names = WeightedNameList::from(vec![("Foo", 1)]);
+			let feminine_names = WeightedNameList::from(vec![("Bar", 1)]);
+			let unisex_names = WeightedNameList::from(vec![("Baz", 1)]);
+			let name_list: GenderedNameList<String> = GenderedNameList::new(Some(masculine_names), Some(feminine_names), Some(unisex_names));
+			let mut rng = thread_rng();
+			assert_eq!("Baz", name_list.sample(&mut rng, Gender::Unisex));
+		}
+
+		/// Verify that sampling with [`Gender::Unisex`] panics when no unisex pool was provided
+		#[test]
+		#[should_panic]
+		fn sample_unisex_missing()
+		{
+			let masculine_names = WeightedNameList::from(vec![("Foo", 1)]);
+			let feminine_names = WeightedNameList::from(vec![("Bar", 1)]);
+			let name_list: GenderedNameList<String> = GenderedNameList::new(Some(masculine_names), Some(feminine_names), None);
+			let mut rng = thread_rng();
+			name_list.sample(&mut rng, Gender::Unisex);
 		}
 	}
 
@@ -194,16 +361,19 @@ mod tests
 		#[test]
 		fn sample()
 		{
-			let given_names = vec![("Foo", 2), ("Bar", 1)];
+			let given_names = GenderedNameList::new(
+				Some(WeightedNameList::from(vec![("Foo", 2), ("Bar", 1)])),
+				Some(WeightedNameList::from(vec![("Fay", 1)])),
+				None,
+			);
 			let family_names = vec![("Baz", 3), ("Buzz", 2)];
-			let given_name_set = WeightedNameList::from(given_names);
 			let family_name_set = WeightedNameList::from(family_names);
-			let name_set: WeightedFullNameList<String, usize> = WeightedFullNameList::new(given_name_set, family_name_set);
+			let name_set: WeightedFullNameList<String> = WeightedFullNameList::new(given_names, family_name_set);
 			let mut names_count: HashMap<String, usize> = HashMap::new();
 			let mut rng = thread_rng();
 			for _ in 0..NAME_COUNT
 			{
-				let (given_name, family_name) = name_set.sample(&mut rng);
+				let (given_name, family_name) = name_set.sample(&mut rng, Gender::Masculine);
 
 				if let Some(count) = names_count.get_mut(given_name)
 				{
@@ -228,5 +398,24 @@ mod tests
 				epsilon = EPSILON,
 			);
 		}
+
+		/// Verify that [`WeightedFullNameList::sample_formatted`] assembles the sampled names
+		/// according to the given [`NameFormat`]
+		#[test]
+		fn sample_formatted()
+		{
+			use crate::format::{NameFormat, NameOrder};
+
+			let given_names = GenderedNameList::new(
+				Some(WeightedNameList::from(vec![("Kenji", 1)])),
+				Some(WeightedNameList::from(vec![("Sachiko", 1)])),
+				None,
+			);
+			let family_names = WeightedNameList::from(vec![("Tanaka", 1)]);
+			let name_set: WeightedFullNameList<String> = WeightedFullNameList::new(given_names, family_names);
+			let format = NameFormat::new(NameOrder::FamilyFirst, " ");
+			let mut rng = thread_rng();
+			assert_eq!("Tanaka Kenji", name_set.sample_formatted(&mut rng, Gender::Masculine, &format));
+		}
 	}
 }
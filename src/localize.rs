@@ -0,0 +1,141 @@
+//! Fluent-backed localization for rendered names.
+//!
+//! Lets a single sampled entry produce a different display string per requested locale - e.g. a
+//! name's Latin, Cyrillic, or Kanji form - by resolving a per-entry Fluent message ID through a
+//! [`FluentBundle`] at render time instead of returning the stored name directly.
+
+use std::collections::HashMap;
+use fluent_bundle::{FluentBundle, FluentResource};
+use unic_langid::LanguageIdentifier;
+use rand::Rng;
+use crate::alias::{AliasTable, IntegerWeight};
+
+
+/// Weighted list of names where each entry can optionally carry a Fluent message ID, resolved
+/// through a locale's [`FluentBundle`] at render time.
+///
+/// This is a separate wrapper type rather than a change to [`crate::WeightedNameList`], so
+/// callers who don't need localized rendering keep using that non-localized API untouched.
+pub struct LocalizedNameList<S>
+{
+	names: Vec<S>,
+	localization_keys: Vec<Option<String>>,
+	weights: AliasTable,
+	bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+}
+
+impl<S> LocalizedNameList<S>
+{
+	/// Creates a new instance. `localization_keys[i]` is the Fluent message ID used to render
+	/// `names[i]` via [`Self::sample_localized`] - pass `None` for an entry that should always
+	/// render as its stored name. `bundles` holds one pre-built [`FluentBundle`] per locale this
+	/// list can render into.
+	///
+	/// # Panics
+	///
+	/// Panics if `names`, `localization_keys`, and `weights` aren't all the same length.
+	pub fn new<W>(
+		names: Vec<S>,
+		localization_keys: Vec<Option<String>>,
+		weights: Vec<W>,
+		bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+	) -> Self
+	where W: IntegerWeight
+	{
+		assert_eq!(names.len(), localization_keys.len(), "names and localization_keys must be the same length");
+		assert_eq!(names.len(), weights.len(), "names and weights must be the same length");
+		Self { weights: AliasTable::new(&weights), names, localization_keys, bundles }
+	}
+
+	/// Samples a single random entry without localizing it. Mirrors
+	/// [`WeightedNameList::sample`](crate::WeightedNameList::sample) for callers that don't need
+	/// Fluent-backed rendering.
+	pub fn sample<R>(&self, rng: &mut R) -> &S
+	where R: Rng + ?Sized
+	{
+		&self.names[self.weights.sample(rng)]
+	}
+
+	/// Samples a single random entry and renders it for `lang_id`.
+	///
+	/// Falls back to the stored name, converted with [`AsRef<str>`], if the entry has no
+	/// localization key, `lang_id` has no bundle, or the bundle has no matching message.
+	pub fn sample_localized<R>(&self, rng: &mut R, lang_id: &LanguageIdentifier) -> String
+	where R: Rng + ?Sized, S: AsRef<str>
+	{
+		let i = self.weights.sample(rng);
+		let fallback = || self.names[i].as_ref().to_string();
+
+		let key = match &self.localization_keys[i] { Some(key) => key, None => return fallback() };
+		let bundle = match self.bundles.get(lang_id) { Some(bundle) => bundle, None => return fallback() };
+		let message = match bundle.get_message(key) { Some(message) => message, None => return fallback() };
+		let pattern = match message.value() { Some(pattern) => pattern, None => return fallback() };
+
+		let mut errors = vec![];
+		bundle.format_pattern(pattern, None, &mut errors).into_owned()
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use rand::thread_rng;
+
+	fn bundle_with_message(lang_id: &str, key: &str, value: &str) -> (LanguageIdentifier, FluentBundle<FluentResource>)
+	{
+		let lang_id: LanguageIdentifier = lang_id.parse().unwrap();
+		let resource = FluentResource::try_new(format!("{key} = {value}")).unwrap();
+		let mut bundle = FluentBundle::new(vec![lang_id.clone()]);
+		bundle.add_resource(resource).unwrap();
+		(lang_id, bundle)
+	}
+
+	fn single_entry_list(key: Option<&str>, bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>) -> LocalizedNameList<String>
+	{
+		LocalizedNameList::new(
+			vec!["Kenji".to_string()],
+			vec![key.map(str::to_string)],
+			vec![1u32],
+			bundles,
+		)
+	}
+
+	#[test]
+	fn sample_localized_renders_matching_bundle()
+	{
+		let (lang_id, bundle) = bundle_with_message("ja", "kenji-name", "健二");
+		let list = single_entry_list(Some("kenji-name"), HashMap::from([(lang_id.clone(), bundle)]));
+		let mut rng = thread_rng();
+		assert_eq!("健二", list.sample_localized(&mut rng, &lang_id));
+	}
+
+	#[test]
+	fn sample_localized_falls_back_with_no_key()
+	{
+		let (lang_id, bundle) = bundle_with_message("ja", "unused", "unused");
+		let list = single_entry_list(None, HashMap::from([(lang_id.clone(), bundle)]));
+		let mut rng = thread_rng();
+		assert_eq!("Kenji", list.sample_localized(&mut rng, &lang_id));
+	}
+
+	#[test]
+	fn sample_localized_falls_back_with_missing_bundle()
+	{
+		let (lang_id, bundle) = bundle_with_message("ja", "kenji-name", "健二");
+		let list = single_entry_list(Some("kenji-name"), HashMap::from([(lang_id, bundle)]));
+		let mut rng = thread_rng();
+		let other: LanguageIdentifier = "ru".parse().unwrap();
+		assert_eq!("Kenji", list.sample_localized(&mut rng, &other));
+	}
+
+	#[test]
+	fn sample_ignores_localization()
+	{
+		let (lang_id, bundle) = bundle_with_message("ja", "kenji-name", "健二");
+		let list = single_entry_list(Some("kenji-name"), HashMap::from([(lang_id, bundle)]));
+		let mut rng = thread_rng();
+		assert_eq!("Kenji", list.sample(&mut rng));
+	}
+}
@@ -0,0 +1,197 @@
+//! Building a [`WeightedFullNameList`] by parsing raw full-name strings, for populating a list
+//! from an observed corpus (a census dump, a credits list, a scraped set of names) instead of
+//! hand-curating a weighted table.
+
+use std::collections::HashMap;
+use crate::{GenderedNameList, WeightedFullNameList, WeightedNameList};
+use crate::format::NameOrder;
+
+
+/// Surname particles that attach to the family name instead of being read as part of the given
+/// name, e.g. "Vincent van Gogh" splits as given `"Vincent"`, family `"van Gogh"`.
+const SURNAME_PARTICLES: &[&str] = &["de", "van", "von"];
+
+fn is_surname_particle(token: &str) -> bool
+{
+	SURNAME_PARTICLES.contains(&token.to_lowercase().as_str())
+}
+
+/// Title-cases a word, except a word that already mixes upper and lower case (e.g. "McDonald",
+/// "O'Brien"), which is assumed to be an intentional style and left untouched.
+fn normalize_word(word: &str) -> String
+{
+	let has_upper = word.chars().any(char::is_uppercase);
+	let has_lower = word.chars().any(char::is_lowercase);
+	if has_upper && has_lower
+	{
+		return word.to_string();
+	}
+	let mut chars = word.chars();
+	match chars.next()
+	{
+		Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+		None => String::new(),
+	}
+}
+
+/// Normalizes a name part made of one or more whitespace-separated tokens, keeping surname
+/// particles lowercase.
+fn normalize_name_part(tokens: &[&str]) -> String
+{
+	tokens.iter()
+		.map(|token| if is_surname_particle(token) { token.to_lowercase() } else { normalize_word(token) })
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Splits a raw full-name string into `(given, family)`, attaching any surname particle to the
+/// family part. Returns `None` for a name with fewer than two tokens, since there's nothing to
+/// split.
+fn split_name(raw: &str, order: NameOrder) -> Option<(String, String)>
+{
+	let tokens: Vec<&str> = raw.split_whitespace().collect();
+	if tokens.len() < 2
+	{
+		return None;
+	}
+
+	let split_at = match order
+	{
+		// Walk the boundary left from the end while the token just past it is a surname
+		// particle, so the family part picks up the whole "van de Berg"-style compound.
+		NameOrder::GivenFirst =>
+		{
+			let mut i = tokens.len() - 1;
+			while i > 1 && is_surname_particle(tokens[i - 1]) { i -= 1; }
+			i
+		}
+		// Mirror image: walk the boundary right from the start while the token just before it
+		// is a surname particle.
+		NameOrder::FamilyFirst =>
+		{
+			let mut i = 1;
+			while i < tokens.len() - 1 && is_surname_particle(tokens[i - 1]) { i += 1; }
+			i
+		}
+	};
+
+	let (given_tokens, family_tokens) = match order
+	{
+		NameOrder::GivenFirst => (&tokens[..split_at], &tokens[split_at..]),
+		NameOrder::FamilyFirst => (&tokens[split_at..], &tokens[..split_at]),
+	};
+
+	Some((normalize_name_part(given_tokens), normalize_name_part(family_tokens)))
+}
+
+/// Turns name observation counts into the `(name, weight)` pairs [`WeightedNameList`] expects.
+fn counts_into_weighted_list(counts: HashMap<String, u32>) -> WeightedNameList<String>
+{
+	WeightedNameList::from(counts.into_iter().collect::<Vec<_>>())
+}
+
+impl WeightedFullNameList<String>
+{
+	/// Builds a weighted full name list by parsing and normalizing a collection of raw full-name
+	/// strings, such as a census dump, a credits list, or a scraped corpus.
+	///
+	/// Each name is tokenized on whitespace and split into a given and family part; surname
+	/// particles like "de", "van", and "von" attach to the family part rather than the given
+	/// name. `order` says whether the given name or family name comes first in the raw strings -
+	/// use [`NameOrder::FamilyFirst`] for a corpus of locale like Japanese. Repeated names are
+	/// deduplicated, with their observed count becoming their sampling weight. Names with no
+	/// family part (a single token) are skipped, since there's nothing to split.
+	///
+	/// Since a raw corpus carries no gender information, the given names are stored in the
+	/// unisex pool of the returned list's [`GenderedNameList`] - sample with [`crate::Gender::Unisex`].
+	///
+	/// # Panics
+	///
+	/// Panics if every raw name is a single token, since skipping them all leaves nothing to
+	/// build a given or family name pool from.
+	pub fn from_raw_names<N, I>(raw_names: I, order: NameOrder) -> Self
+	where N: AsRef<str>, I: IntoIterator<Item = N>
+	{
+		let mut given_counts: HashMap<String, u32> = HashMap::new();
+		let mut family_counts: HashMap<String, u32> = HashMap::new();
+		for raw in raw_names
+		{
+			if let Some((given, family)) = split_name(raw.as_ref(), order)
+			{
+				*given_counts.entry(given).or_insert(0) += 1;
+				*family_counts.entry(family).or_insert(0) += 1;
+			}
+		}
+
+		let given_names = GenderedNameList::new(None, None, Some(counts_into_weighted_list(given_counts)));
+		let family_names = counts_into_weighted_list(family_counts);
+		Self::new(given_names, family_names)
+	}
+}
+
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::Gender;
+	use rand::thread_rng;
+
+	#[test]
+	fn splits_given_first_names()
+	{
+		let (given, family) = split_name("Vincent van Gogh", NameOrder::GivenFirst).unwrap();
+		assert_eq!("Vincent", given);
+		assert_eq!("van Gogh", family);
+	}
+
+	#[test]
+	fn splits_family_first_names()
+	{
+		let (given, family) = split_name("van Gogh Vincent", NameOrder::FamilyFirst).unwrap();
+		assert_eq!("Vincent", given);
+		assert_eq!("van Gogh", family);
+	}
+
+	#[test]
+	fn normalizes_inconsistent_casing()
+	{
+		let (given, family) = split_name("JOHN smith", NameOrder::GivenFirst).unwrap();
+		assert_eq!("John", given);
+		assert_eq!("Smith", family);
+	}
+
+	#[test]
+	fn preserves_intentional_mixed_case()
+	{
+		let (_, family) = split_name("Conor McDonald", NameOrder::GivenFirst).unwrap();
+		assert_eq!("McDonald", family);
+	}
+
+	#[test]
+	fn skips_single_token_names()
+	{
+		assert!(split_name("Cher", NameOrder::GivenFirst).is_none());
+	}
+
+	#[test]
+	fn from_raw_names_deduplicates_into_weights()
+	{
+		let raw_names = vec!["John Smith", "John Smith", "Jane Smith"];
+		let name_list = WeightedFullNameList::from_raw_names(raw_names, NameOrder::GivenFirst);
+		let mut rng = thread_rng();
+		let (_, family) = name_list.sample(&mut rng, Gender::Unisex);
+		assert_eq!("Smith", family);
+
+		// "John" was observed twice and "Jane" once, so "John" should be sampled about twice as often
+		let mut count_john = 0;
+		let mut count_jane = 0;
+		for _ in 0..3000
+		{
+			let (given, _) = name_list.sample(&mut rng, Gender::Unisex);
+			if given == "John" { count_john += 1; } else if given == "Jane" { count_jane += 1; }
+			else { panic!("Expected given name to be either \"John\" or \"Jane\", but \"{}\" was returned", given); }
+		}
+		approx::assert_ulps_eq!(2.0, count_john as f32 / count_jane as f32, epsilon = 0.2);
+	}
+}
@@ -1,19 +1,23 @@
 //! Premade name lists for easy use in game
 
 
-use crate::{WeightedNameList, WeightedFullNameList};
+use crate::{GenderedNameList, WeightedNameList, WeightedFullNameList};
+use crate::format::{NameFormat, NameOrder};
 
 
 /// Preset name lists by locale.
-/// 
+///
 /// These are based on census data collected by world governments and may not perfectly represent
 /// the cultures in those nations. Don't @ me.
+///
+/// Weights are the source frequency percentages scaled up by 1000 and rounded to the nearest
+/// integer, so they can be sampled exactly by [`crate::alias::AliasTable`].
 pub mod locale
 {
 	use super::*;
 
 	/// Preset list of names weighted by their frequency in the nation of Japan.
-	/// 
+	///
 	/// This is included as an example of a name set that requires the generic terms "given" and
 	/// "family" names to be used, as "first" and "last" names are reversed in japanese, making
 	/// terms like these confusing or contradictory.
@@ -21,43 +25,69 @@ pub mod locale
 	{
 		use super::*;
 
-		/// Common given names in japan
-		/// 
+		/// Common masculine given names in Japan
+		///
 		/// Source: <https://forebears.io/japan/forenames>
-		pub fn given_names() -> WeightedNameList<String, f32>
+		pub fn masculine_given_names() -> WeightedNameList<String>
 		{
 			let names = vec![
-				("Kenji", 1.545), ("Hiroshi", 1.511), ("Shigeru", 1.208), ("Sachiko", 1.042),
-				("Masako", 1.009), ("Katsumi", 0.989), ("Yoko", 0.959), ("Michiko", 0.911),
-				("Toshio", 0.871), ("Yoshiko", 0.871), ("Hiromi", 0.830), ("Hiroko", 0.826),
-				("Yoshio", 0.790), ("Kazuo", 0.760), ("Akira", 0.753), ("Keiko", 0.739),
-				("Hisako", 0.728), ("Yoshimi", 0.705), ("Fumiko", 0.675), ("Masao", 0.671),
+				("Kenji", 1545), ("Hiroshi", 1511), ("Shigeru", 1208), ("Katsumi", 989),
+				("Toshio", 871), ("Yoshio", 790), ("Kazuo", 760), ("Akira", 753),
+				("Masao", 671),
 			];
 			WeightedNameList::from(names)
 		}
 
+		/// Common feminine given names in Japan
+		///
+		/// Source: <https://forebears.io/japan/forenames>
+		pub fn feminine_given_names() -> WeightedNameList<String>
+		{
+			let names = vec![
+				("Sachiko", 1042), ("Masako", 1009), ("Yoko", 959), ("Michiko", 911),
+				("Yoshiko", 871), ("Hiromi", 830), ("Hiroko", 826), ("Keiko", 739),
+				("Hisako", 728), ("Yoshimi", 705), ("Fumiko", 675),
+			];
+			WeightedNameList::from(names)
+		}
+
+		/// Common given names in Japan, split by gender
+		///
+		/// Source: see [`masculine_given_names`] and [`feminine_given_names`]
+		pub fn given_names() -> GenderedNameList<String>
+		{
+			GenderedNameList::new(Some(masculine_given_names()), Some(feminine_given_names()), None)
+		}
+
 		/// Common family names in Japan
-		/// 
+		///
 		/// Source: <https://forebears.io/japan/surnames>
-		pub fn family_names() -> WeightedNameList<String, f32>
+		pub fn family_names() -> WeightedNameList<String>
 		{
 			let names = vec![
-				("Sato", 1.957), ("Suzuki", 1.889), ("Tanaka", 1.414), ("Watanabe", 1.364),
-				("Takahashi", 1.343), ("Ito", 1.240), ("Yamamoto", 1.131), ("Nakamura", 1.124),
-				("Kobayashi", 1.075), ("Saito", 1.038), ("Kato", 0.936), ("Yoshida", 0.867),
-				("Yamada", 0.848), ("Sasaki", 0.707), ("Matsumoto", 0.685), ("Yamaguchi", 0.674),
-				("Inoue", 0.649), ("Kimura", 0.601), ("Shimizu", 0.574), ("Hayashi", 0.572),
+				("Sato", 1957), ("Suzuki", 1889), ("Tanaka", 1414), ("Watanabe", 1364),
+				("Takahashi", 1343), ("Ito", 1240), ("Yamamoto", 1131), ("Nakamura", 1124),
+				("Kobayashi", 1075), ("Saito", 1038), ("Kato", 936), ("Yoshida", 867),
+				("Yamada", 848), ("Sasaki", 707), ("Matsumoto", 685), ("Yamaguchi", 674),
+				("Inoue", 649), ("Kimura", 601), ("Shimizu", 574), ("Hayashi", 572),
 			];
 			WeightedNameList::from(names)
 		}
 
 		/// Common names in Japan
-		/// 
+		///
 		/// Source: see [`given_names`] and [`family_names`]
-		pub fn full_names() -> WeightedFullNameList<String, f32>
+		pub fn full_names() -> WeightedFullNameList<String>
 		{
 			WeightedFullNameList::new(given_names(), family_names())
 		}
+
+		/// Default rendering format for Japanese names: family name first, as "first" and "last"
+		/// names are reversed in Japanese compared to given-first locales like [`super::us`].
+		pub fn default_format() -> NameFormat
+		{
+			NameFormat::new(NameOrder::FamilyFirst, " ")
+		}
 	}
 
 	/// Preset list of names weighted by their frequency in the Russian Federation
@@ -65,43 +95,68 @@ pub mod locale
 	{
 		use super::*;
 
-		/// Most common given names in Russia
-		/// 
+		/// Most common masculine given names in Russia
+		///
+		/// Source: <https://forebears.io/russia/forenames>
+		pub fn masculine_given_names() -> WeightedNameList<String>
+		{
+			let names = vec![
+				("Sergey", 4943), ("Aleksandr", 4530), ("Andrey", 3487), ("Dmitriy", 3196),
+				("Vladimir", 2940), ("Aleksey", 2850), ("Maksim", 1910), ("Ivan", 1834),
+				("Evgeniy", 1799), ("Alexander", 1748),
+			];
+			WeightedNameList::from(names)
+		}
+
+		/// Most common feminine given names in Russia
+		///
 		/// Source: <https://forebears.io/russia/forenames>
-		pub fn given_names() -> WeightedNameList<String, f32>
+		pub fn feminine_given_names() -> WeightedNameList<String>
 		{
 			let names = vec![
-				("Sergey", 4.943), ("Aleksandr", 4.530), ("Elena", 4.312), ("Tatyana", 3.744),
-				("Olga", 3.609), ("Natalya", 3.605), ("Andrey", 3.487), ("Ekaterina", 3.285),
-				("Dmitriy", 3.196), ("Irina", 3.030), ("Vladimir", 2.940), ("Aleksey", 2.850),
-				("Svetlana", 2.768), ("Anastasiya", 2.769), ("Anna", 2.278), ("Maksim", 1.910),
-				("Marina", 1.882), ("Ivan", 1.834), ("Evgeniy", 1.799), ("Alexander", 1.748),
+				("Elena", 4312), ("Tatyana", 3744), ("Olga", 3609), ("Natalya", 3605),
+				("Ekaterina", 3285), ("Irina", 3030), ("Svetlana", 2768), ("Anastasiya", 2769),
+				("Anna", 2278), ("Marina", 1882),
 			];
 			WeightedNameList::from(names)
 		}
 
+		/// Most common given names in the Russian Federation, split by gender
+		///
+		/// Source: see [`masculine_given_names`] and [`feminine_given_names`]
+		pub fn given_names() -> GenderedNameList<String>
+		{
+			GenderedNameList::new(Some(masculine_given_names()), Some(feminine_given_names()), None)
+		}
+
 		/// Most common family names in the Russian Federation
-		/// 
+		///
 		/// Source: <https://forebears.io/russia/surnames>
-		pub fn family_names() -> WeightedNameList<String, f32>
+		pub fn family_names() -> WeightedNameList<String>
 		{
 			let names = vec![
-				("Ivanova", 0.928), ("Ivanov", 0.881), ("Kuznetsova", 0.454), ("Kuznetsov", 0.437),
-				("Petrov", 0.430), ("Smirnova", 0.428), ("Magomedov", 0.385), ("Petrova", 0.383),
-				("Smirnov", 0.366), ("Popov", 0.366), ("Popova", 0.366), ("Volkova", 0.304),
-				("Novikova", 0.258), ("Morozova", 0.240), ("Sokolova", 0.230), ("Pavlova", 0.223),
-				("Romanova", 0.222), ("Volkov", 0.219), ("Shevchenko", 0.218), ("Andreeva", 0.216),
+				("Ivanova", 928), ("Ivanov", 881), ("Kuznetsova", 454), ("Kuznetsov", 437),
+				("Petrov", 430), ("Smirnova", 428), ("Magomedov", 385), ("Petrova", 383),
+				("Smirnov", 366), ("Popov", 366), ("Popova", 366), ("Volkova", 304),
+				("Novikova", 258), ("Morozova", 240), ("Sokolova", 230), ("Pavlova", 223),
+				("Romanova", 222), ("Volkov", 219), ("Shevchenko", 218), ("Andreeva", 216),
 			];
 			WeightedNameList::from(names)
 		}
 
 		/// Most common names in the Russian Federation
-		/// 
+		///
 		/// Source: see [`given_names`] and [`full_names`]
-		pub fn full_names() -> WeightedFullNameList<String, f32>
+		pub fn full_names() -> WeightedFullNameList<String>
 		{
 			WeightedFullNameList::new(given_names(), family_names())
 		}
+
+		/// Default rendering format for Russian names: given name first, as in [`super::us`].
+		pub fn default_format() -> NameFormat
+		{
+			NameFormat::new(NameOrder::GivenFirst, " ")
+		}
 	}
 
 	/// Preset name lists weighted by their frequency in the United States of America
@@ -109,43 +164,67 @@ pub mod locale
 	{
 		use super::*;
 
-		/// Premade list of given names in the United States of America
-		/// 
+		/// Premade list of masculine given names in the United States of America
+		///
 		/// Source: <https://namecensus.com/first-names/>
-		pub fn given_names() -> WeightedNameList<String, f32>
+		pub fn masculine_given_names() -> WeightedNameList<String>
 		{
 			let names = vec![
-				("James", 10.836), ("John", 10.682), ("Robert", 10.264), ("Mary", 8.586),
-				("Michael", 8.586), ("William", 8.004), ("David", 7.717), ("Richard", 5.561),
-				("Charles", 4.974), ("Joseph", 4.585), ("Thomas", 4.507), ("Patricia", 3.504),
-				("Linda", 3.380), ("Barbara", 3.200), ("Elizabeth", 3.060), ("Jennifer", 3.044),
-				("Maria", 2.704), ("Susan", 2.593), ("Margaret", 2.508), ("Dorothy", 2.374),
-				 
+				("James", 10836), ("John", 10682), ("Robert", 10264), ("Michael", 8586),
+				("William", 8004), ("David", 7717), ("Richard", 5561), ("Charles", 4974),
+				("Joseph", 4585), ("Thomas", 4507),
 			];
 			WeightedNameList::from(names)
 		}
 
+		/// Premade list of feminine given names in the United States of America
+		///
+		/// Source: <https://namecensus.com/first-names/>
+		pub fn feminine_given_names() -> WeightedNameList<String>
+		{
+			let names = vec![
+				("Mary", 8586), ("Patricia", 3504), ("Linda", 3380), ("Barbara", 3200),
+				("Elizabeth", 3060), ("Jennifer", 3044), ("Maria", 2704), ("Susan", 2593),
+				("Margaret", 2508), ("Dorothy", 2374),
+			];
+			WeightedNameList::from(names)
+		}
+
+		/// Premade list of given names in the United States of America, split by gender
+		///
+		/// Source: see [`masculine_given_names`] and [`feminine_given_names`]
+		pub fn given_names() -> GenderedNameList<String>
+		{
+			GenderedNameList::new(Some(masculine_given_names()), Some(feminine_given_names()), None)
+		}
+
 		/// Premade list of family nams in the United States of America
-		/// 
+		///
 		/// Source: <https://www.thoughtco.com/most-common-us-surnames-1422656>
-		pub fn family_names() -> WeightedNameList<String, f32>
+		pub fn family_names() -> WeightedNameList<String>
 		{
 			let names = vec![
-				("Smith", 2.443), ("Johnson", 1.933), ("Williams", 1.625), ("Brown", 1.437),
-				("Jones", 1.425), ("Garcia", 1.166), ("Miller", 1.161), ("Davis", 1.116),
-				("Rodriguez", 1.095), ("Martinez", 1.060), ("Hernandez", 1.040), ("Lopez", 0.875),
-				("Gonzalez", 0.841), ("Wilson", 0.802), ("Anderson", 0.784), ("Thomas", 0.756),
-				("Taylor", 0.751), ("Moore", 0.724), ("Jackson", 0.708), ("Martin", 0.703),
+				("Smith", 2443), ("Johnson", 1933), ("Williams", 1625), ("Brown", 1437),
+				("Jones", 1425), ("Garcia", 1166), ("Miller", 1161), ("Davis", 1116),
+				("Rodriguez", 1095), ("Martinez", 1060), ("Hernandez", 1040), ("Lopez", 875),
+				("Gonzalez", 841), ("Wilson", 802), ("Anderson", 784), ("Thomas", 756),
+				("Taylor", 751), ("Moore", 724), ("Jackson", 708), ("Martin", 703),
 			];
 			WeightedNameList::from(names)
 		}
 
 		/// Premade list of full names in the United States of America
-		/// 
+		///
 		/// Sources: see [`given_names`] and [`family_names`]
-		pub fn full_names() -> WeightedFullNameList<String, f32>
+		pub fn full_names() -> WeightedFullNameList<String>
 		{
 			WeightedFullNameList::new(given_names(), family_names())
 		}
+
+		/// Default rendering format for American names: given name first.
+		pub fn default_format() -> NameFormat
+		{
+			NameFormat::new(NameOrder::GivenFirst, " ")
+		}
 	}
-}
\ No newline at end of file
+}